@@ -8,10 +8,11 @@
 //!
 //! Let's get through the functionality and configurations provided by this GPIO
 //! module:
-//!   - `io_mux_reg(gpio_num: u8) -> &'static io_mux::GPIO0:`:
+//!   - `io_mux_reg(gpio_num: u8) -> Option<&'static io_mux::GPIO0>:`:
 //!       * This function returns a reference to the GPIO register associated
-//!         with the given GPIO number. It uses unsafe code and transmutation to
-//!         access the GPIO registers based on the provided GPIO number.
+//!         with the given GPIO number, or `None` if it doesn't exist. It looks
+//!         up the register's offset in a table and performs a single checked
+//!         pointer cast, since every IO_MUX pin register shares one layout.
 //!   - `gpio_intr_enable(int_enable: bool, nmi_enable: bool) -> u8`:
 //!       * This function enables or disables GPIO interrupts and Non-Maskable
 //!         Interrupts (NMI). It takes two boolean arguments int_enable and
@@ -40,12 +41,19 @@
 //! registers for both the `PRO CPU` and `APP CPU`. The implementation uses the
 //! `gpio` peripheral to access the appropriate registers.
 
-use core::mem::transmute;
+use core::{
+    cell::RefCell,
+    future::Future,
+    pin::Pin,
+    sync::atomic::{AtomicU16, Ordering},
+    task::{Context, Poll, Waker},
+};
 
 use crate::{
-    gpio::AlternateFunction,
+    gpio::{AlternateFunction, RtcPin, TouchPin},
     pac::io_mux,
-    peripherals::{GPIO, IO_MUX},
+    peripherals::{GPIO, IO_MUX, LPWR, SENS},
+    private::Internal,
     system::Cpu,
 };
 
@@ -61,50 +69,138 @@ pub(crate) const ZERO_INPUT: u8 = 0x30;
 
 pub(crate) const GPIO_FUNCTION: AlternateFunction = AlternateFunction::_2;
 
-pub(crate) fn io_mux_reg(gpio_num: u8) -> &'static io_mux::GPIO0 {
-    let iomux = IO_MUX::regs();
-
-    unsafe {
-        match gpio_num {
-            0 => transmute::<&'static io_mux::GPIO0, &'static io_mux::GPIO0>(iomux.gpio0()),
-            1 => transmute::<&'static io_mux::GPIO1, &'static io_mux::GPIO0>(iomux.gpio1()),
-            2 => transmute::<&'static io_mux::GPIO2, &'static io_mux::GPIO0>(iomux.gpio2()),
-            3 => transmute::<&'static io_mux::GPIO3, &'static io_mux::GPIO0>(iomux.gpio3()),
-            4 => transmute::<&'static io_mux::GPIO4, &'static io_mux::GPIO0>(iomux.gpio4()),
-            5 => transmute::<&'static io_mux::GPIO5, &'static io_mux::GPIO0>(iomux.gpio5()),
-            6 => transmute::<&'static io_mux::GPIO6, &'static io_mux::GPIO0>(iomux.gpio6()),
-            7 => transmute::<&'static io_mux::GPIO7, &'static io_mux::GPIO0>(iomux.gpio7()),
-            8 => transmute::<&'static io_mux::GPIO8, &'static io_mux::GPIO0>(iomux.gpio8()),
-            9 => transmute::<&'static io_mux::GPIO9, &'static io_mux::GPIO0>(iomux.gpio9()),
-            10 => transmute::<&'static io_mux::GPIO10, &'static io_mux::GPIO0>(iomux.gpio10()),
-            11 => transmute::<&'static io_mux::GPIO11, &'static io_mux::GPIO0>(iomux.gpio11()),
-            12 => transmute::<&'static io_mux::GPIO12, &'static io_mux::GPIO0>(iomux.gpio12()),
-            13 => transmute::<&'static io_mux::GPIO13, &'static io_mux::GPIO0>(iomux.gpio13()),
-            14 => transmute::<&'static io_mux::GPIO14, &'static io_mux::GPIO0>(iomux.gpio14()),
-            15 => transmute::<&'static io_mux::GPIO15, &'static io_mux::GPIO0>(iomux.gpio15()),
-            16 => transmute::<&'static io_mux::GPIO16, &'static io_mux::GPIO0>(iomux.gpio16()),
-            17 => transmute::<&'static io_mux::GPIO17, &'static io_mux::GPIO0>(iomux.gpio17()),
-            18 => transmute::<&'static io_mux::GPIO18, &'static io_mux::GPIO0>(iomux.gpio18()),
-            19 => transmute::<&'static io_mux::GPIO19, &'static io_mux::GPIO0>(iomux.gpio19()),
-            20 => transmute::<&'static io_mux::GPIO20, &'static io_mux::GPIO0>(iomux.gpio20()),
-            21 => transmute::<&'static io_mux::GPIO21, &'static io_mux::GPIO0>(iomux.gpio21()),
-            22 => transmute::<&'static io_mux::GPIO22, &'static io_mux::GPIO0>(iomux.gpio22()),
-            23 => transmute::<&'static io_mux::GPIO23, &'static io_mux::GPIO0>(iomux.gpio23()),
-            24 => transmute::<&'static io_mux::GPIO24, &'static io_mux::GPIO0>(iomux.gpio24()),
-            25 => transmute::<&'static io_mux::GPIO25, &'static io_mux::GPIO0>(iomux.gpio25()),
-            26 => transmute::<&'static io_mux::GPIO26, &'static io_mux::GPIO0>(iomux.gpio26()),
-            27 => transmute::<&'static io_mux::GPIO27, &'static io_mux::GPIO0>(iomux.gpio27()),
-            32 => transmute::<&'static io_mux::GPIO32, &'static io_mux::GPIO0>(iomux.gpio32()),
-            33 => transmute::<&'static io_mux::GPIO33, &'static io_mux::GPIO0>(iomux.gpio33()),
-            34 => transmute::<&'static io_mux::GPIO34, &'static io_mux::GPIO0>(iomux.gpio34()),
-            35 => transmute::<&'static io_mux::GPIO35, &'static io_mux::GPIO0>(iomux.gpio35()),
-            36 => transmute::<&'static io_mux::GPIO36, &'static io_mux::GPIO0>(iomux.gpio36()),
-            37 => transmute::<&'static io_mux::GPIO37, &'static io_mux::GPIO0>(iomux.gpio37()),
-            38 => transmute::<&'static io_mux::GPIO38, &'static io_mux::GPIO0>(iomux.gpio38()),
-            39 => transmute::<&'static io_mux::GPIO39, &'static io_mux::GPIO0>(iomux.gpio39()),
-            other => panic!("GPIO {} does not exist", other),
-        }
-    }
+/// Byte offset of each GPIO's register within the IO_MUX block, indexed by
+/// GPIO number. `None` marks a GPIO number with no IO_MUX register at all
+/// (28..=31, plus anything out of range).
+///
+/// Every `io_mux::GPIOn` register shares the exact same layout as
+/// `io_mux::GPIO0` -- only its position in the block differs -- so one
+/// offset table plus a single checked cast replaces the old per-pin
+/// transmute match.
+const GPIO_IOMUX_OFFSET: [Option<u16>; 40] = {
+    let mut table = [None; 40];
+    table[0] = Some(0x44);
+    table[1] = Some(0x88);
+    table[2] = Some(0x40);
+    table[3] = Some(0x84);
+    table[4] = Some(0x48);
+    table[5] = Some(0x6c);
+    table[6] = Some(0x60);
+    table[7] = Some(0x64);
+    table[8] = Some(0x68);
+    table[9] = Some(0x54);
+    table[10] = Some(0x58);
+    table[11] = Some(0x5c);
+    table[12] = Some(0x34);
+    table[13] = Some(0x38);
+    table[14] = Some(0x30);
+    table[15] = Some(0x3c);
+    table[16] = Some(0x4c);
+    table[17] = Some(0x50);
+    table[18] = Some(0x70);
+    table[19] = Some(0x74);
+    table[20] = Some(0x78);
+    table[21] = Some(0x7c);
+    table[22] = Some(0x80);
+    table[23] = Some(0x8c);
+    table[24] = Some(0x90);
+    table[25] = Some(0x24);
+    table[26] = Some(0x28);
+    table[27] = Some(0x2c);
+    // 28..=31 do not exist on the ESP32.
+    table[32] = Some(0x1c);
+    table[33] = Some(0x20);
+    table[34] = Some(0x14);
+    table[35] = Some(0x18);
+    table[36] = Some(0x04);
+    table[37] = Some(0x08);
+    table[38] = Some(0x0c);
+    table[39] = Some(0x10);
+    table
+};
+
+/// Returns the IO_MUX register block for `gpio_num`, or `None` if the pin
+/// does not exist.
+pub(crate) fn io_mux_reg(gpio_num: u8) -> Option<&'static io_mux::GPIO0> {
+    let offset = (*GPIO_IOMUX_OFFSET.get(gpio_num as usize)?)?;
+    let base = IO_MUX::regs() as *const _ as *const u8;
+
+    // SAFETY: `offset` is only ever one of the pin offsets recorded in
+    // `GPIO_IOMUX_OFFSET` above, and every `io_mux::GPIOn` register has the
+    // identical layout as `io_mux::GPIO0`.
+    Some(unsafe { &*(base.add(offset as usize) as *const io_mux::GPIO0) })
+}
+
+/// Native (non-matrix) IO_MUX routes for input signals.
+///
+/// A subset of peripheral signals can be wired straight through the IO_MUX
+/// on one specific "native" pin instead of being routed through the GPIO
+/// matrix. Native routes support clock rates up to ~80 MHz versus the
+/// matrix's ~40 MHz ceiling, at the cost of not being able to pick an
+/// arbitrary pin for the signal.
+const INPUT_DIRECT_ROUTES: &[(InputSignal, u8, AlternateFunction)] = &[
+    (InputSignal::U0RXD, 3, AlternateFunction::_0),
+    (InputSignal::SPICLK, 6, AlternateFunction::_1),
+    (InputSignal::SPIQ, 7, AlternateFunction::_1),
+    (InputSignal::SPID, 8, AlternateFunction::_1),
+    (InputSignal::SPIHD, 9, AlternateFunction::_1),
+    (InputSignal::SPIWP, 10, AlternateFunction::_1),
+    (InputSignal::SPICS0, 11, AlternateFunction::_1),
+    (InputSignal::HSPIQ, 12, AlternateFunction::_1),
+    (InputSignal::HSPID, 13, AlternateFunction::_1),
+    (InputSignal::HSPICLK, 14, AlternateFunction::_1),
+    (InputSignal::HSPICS0, 15, AlternateFunction::_1),
+    (InputSignal::VSPICLK, 18, AlternateFunction::_1),
+    (InputSignal::VSPIQ, 19, AlternateFunction::_1),
+    (InputSignal::VSPID, 23, AlternateFunction::_1),
+];
+
+/// Native (non-matrix) IO_MUX routes for output signals. See
+/// [`INPUT_DIRECT_ROUTES`].
+const OUTPUT_DIRECT_ROUTES: &[(OutputSignal, u8, AlternateFunction)] = &[
+    (OutputSignal::U0TXD, 1, AlternateFunction::_0),
+    (OutputSignal::SPICLK, 6, AlternateFunction::_1),
+    (OutputSignal::SPIQ, 7, AlternateFunction::_1),
+    (OutputSignal::SPID, 8, AlternateFunction::_1),
+    (OutputSignal::SPIHD, 9, AlternateFunction::_1),
+    (OutputSignal::SPIWP, 10, AlternateFunction::_1),
+    (OutputSignal::SPICS0, 11, AlternateFunction::_1),
+    (OutputSignal::HSPIQ, 12, AlternateFunction::_1),
+    (OutputSignal::HSPID, 13, AlternateFunction::_1),
+    (OutputSignal::HSPICLK, 14, AlternateFunction::_1),
+    (OutputSignal::HSPICS0, 15, AlternateFunction::_1),
+    (OutputSignal::VSPICLK, 18, AlternateFunction::_1),
+    (OutputSignal::VSPIQ, 19, AlternateFunction::_1),
+    (OutputSignal::VSPID, 23, AlternateFunction::_1),
+];
+
+/// Returns the IO_MUX alternate function that routes `signal` directly to
+/// `gpio_num` without passing through the GPIO matrix, if one exists.
+///
+/// Callers (e.g. the SPI driver picking a fast clock/data pin) can use this
+/// to program the pin's IO_MUX `mcu_sel` directly instead of going through
+/// [`connect_peripheral_to_output`](crate::gpio::connect_peripheral_to_output)
+/// / matrix input selection. When this returns `None`, the signal must be
+/// routed through the matrix on the requested pin.
+pub(crate) fn direct_io_mux_function(signal: InputSignal, gpio_num: u8) -> Option<AlternateFunction> {
+    INPUT_DIRECT_ROUTES
+        .iter()
+        .find(|(s, g, _)| *s == signal && *g == gpio_num)
+        .map(|(_, _, function)| *function)
+}
+
+/// Returns the IO_MUX alternate function that routes `signal` directly out
+/// of `gpio_num` without passing through the GPIO matrix, if one exists.
+///
+/// See [`direct_io_mux_function`] for the input-signal equivalent.
+pub(crate) fn direct_io_mux_function_output(
+    signal: OutputSignal,
+    gpio_num: u8,
+) -> Option<AlternateFunction> {
+    OUTPUT_DIRECT_ROUTES
+        .iter()
+        .find(|(s, g, _)| *s == signal && *g == gpio_num)
+        .map(|(_, _, function)| *function)
 }
 
 pub(crate) fn gpio_intr_enable(int_enable: bool, nmi_enable: bool) -> u8 {
@@ -748,6 +844,126 @@ rtcio_analog! {
     (27, 17, touch_pad7(),     "",      touch_pad7, true)
 }
 
+/// Extension methods for freezing an RTC-capable pin's configuration across
+/// deep sleep, built on [`RtcPin::rtcio_pad_hold`].
+///
+/// A held pin keeps driving its current level (or keeps its RTC routing,
+/// for an armed touch pad) while the digital core is powered down. The pin
+/// must be [`unhold`](RtcPinHold::unhold)ed before it can be reconfigured;
+/// reconfiguring a held pin has no effect until it is released.
+pub trait RtcPinHold: RtcPin {
+    /// Freezes the pin's current configuration so it survives deep sleep and
+    /// the wake transition.
+    fn hold(&self) {
+        self.rtcio_pad_hold(true);
+    }
+
+    /// Releases a hold previously set with [`hold`](RtcPinHold::hold).
+    fn unhold(&self) {
+        self.rtcio_pad_hold(false);
+    }
+}
+
+impl<T: RtcPin> RtcPinHold for T {}
+
+/// Freezes every *digital* (GPIO-matrix-routed) pad at its current output
+/// level for the duration of deep sleep.
+///
+/// This is a one-shot pulse into a different hardware domain than
+/// [`RtcPinHold::hold`]: it does not gate, and is not needed by, the
+/// per-pin RTC/analog hold bits `RtcPinHold` sets (those already fully
+/// control their own pin with no master enable). Use this only for regular
+/// digital GPIOs that aren't RTC-capable.
+///
+/// As with `RtcPinHold::hold`, the ESP32 does *not* release this
+/// automatically on wake -- call [`release_digital_pad_hold`] in software
+/// after waking, before reconfiguring any digital pad.
+pub fn hold_all_digital_pads() {
+    LPWR::regs()
+        .dig_iso()
+        .modify(|_, w| w.dg_pad_force_hold().set_bit());
+}
+
+/// Releases a hold previously set with [`hold_all_digital_pads`].
+pub fn release_digital_pad_hold() {
+    LPWR::regs()
+        .dig_iso()
+        .modify(|_, w| w.dg_pad_force_unhold().set_bit());
+}
+
+/// Level that triggers an [`Ext0WakeupSource`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum WakeupLevel {
+    Low,
+    High,
+}
+
+/// Deep-sleep wakeup source that wakes when a single RTC-capable GPIO
+/// reaches `level`.
+pub struct Ext0WakeupSource<'a, P: RtcPin> {
+    pin: &'a P,
+    level: WakeupLevel,
+}
+
+impl<'a, P: RtcPin> Ext0WakeupSource<'a, P> {
+    pub fn new(pin: &'a P, level: WakeupLevel) -> Self {
+        Self { pin, level }
+    }
+
+    /// Programs the RTC_CNTL ext0 wakeup registers and enables ext0 as a
+    /// wakeup source for the next deep sleep.
+    pub fn enable(&self) {
+        let lpwr = LPWR::regs();
+
+        lpwr.ext_wakeup0()
+            .modify(|_, w| unsafe { w.sel().bits(self.pin.rtc_number()) });
+        lpwr.ext_wakeup0()
+            .modify(|_, w| w.lv().bit(self.level == WakeupLevel::High));
+        lpwr.wakeup_state()
+            .modify(|_, w| w.ext0_wakeup_en().set_bit());
+    }
+}
+
+/// Wakeup condition for [`Ext1WakeupSource`]: wake as soon as any selected
+/// pin reads high, or only once every selected pin reads low.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Ext1WakeupMode {
+    AnyHigh,
+    AllLow,
+}
+
+/// Deep-sleep wakeup source that wakes on a set of RTC-capable GPIOs,
+/// combined via [`Ext1WakeupMode`].
+pub struct Ext1WakeupSource<'a> {
+    pins: &'a [&'a dyn RtcPin],
+    mode: Ext1WakeupMode,
+}
+
+impl<'a> Ext1WakeupSource<'a> {
+    pub fn new(pins: &'a [&'a dyn RtcPin], mode: Ext1WakeupMode) -> Self {
+        Self { pins, mode }
+    }
+
+    /// Programs the RTC_CNTL ext1 wakeup mask/mode and enables ext1 as a
+    /// wakeup source for the next deep sleep.
+    pub fn enable(&self) {
+        let mask = self
+            .pins
+            .iter()
+            .fold(0u32, |mask, pin| mask | (1 << pin.rtc_number()));
+
+        let lpwr = LPWR::regs();
+        lpwr.ext_wakeup1()
+            .modify(|_, w| unsafe { w.sel().bits(mask) });
+        lpwr.ext_wakeup1_lv()
+            .modify(|_, w| w.ext_wakeup1_lv().bit(self.mode == Ext1WakeupMode::AnyHigh));
+        lpwr.wakeup_state()
+            .modify(|_, w| w.ext1_wakeup_en().set_bit());
+    }
+}
+
 touch! {
     // touch_nr, pin_nr, touch_out_reg, touch_thres_reg, normal_pin
     (0, 4,  sar_touch_out1, sar_touch_thres1, true)
@@ -763,6 +979,556 @@ touch! {
     (9, 32, sar_touch_out5, sar_touch_thres5, false)
 }
 
+/// Number of capacitive touch channels (`touch_nr` 0..=9) on the ESP32.
+pub const TOUCH_CHANNEL_COUNT: usize = 10;
+
+/// Touch channels that double as strapping pins (GPIO0, GPIO2, GPIO12,
+/// GPIO15) and must not be armed, since touching them could be
+/// misinterpreted as a boot-mode strap.
+const TOUCH_RESERVED_CHANNELS: u16 = (1 << 1) | (1 << 2) | (1 << 3) | (1 << 5);
+
+/// Default number of SAR ADC clock cycles the touch FSM charges/discharges a
+/// pad for during one measurement.
+const DEFAULT_MEAS_CYCLE: u16 = 0x1000;
+
+/// Default number of RTC slow-clock cycles the touch FSM sleeps between
+/// measurement sweeps.
+const DEFAULT_SLEEP_CYCLE: u8 = 0x10;
+
+/// Error returned by [`Touch`] operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TouchError {
+    /// The touch channel shares a pin with a strapping or flash pin and
+    /// cannot be used.
+    ReservedChannel,
+}
+
+/// Touch FSM timing and charge-pump configuration, applied when a [`Touch`]
+/// controller is started with [`Touch::with_config`].
+///
+/// `meas_cycle` and `sleep_cycle` set the measurement window and the idle
+/// time between sweeps; `charge_high`/`charge_low` set the comparator
+/// reference voltages the pad charges between, and `charge_speed` sets how
+/// fast it's driven between them. Together these are the knobs that decide
+/// how many counts a touch produces versus how fast the FSM scans and how
+/// much noise it picks up.
+#[derive(Debug, Clone, Copy)]
+pub struct TouchConfig {
+    pub meas_cycle: u16,
+    pub sleep_cycle: u8,
+    pub charge_high: u8,
+    pub charge_low: u8,
+    pub charge_speed: u8,
+}
+
+impl Default for TouchConfig {
+    fn default() -> Self {
+        Self {
+            meas_cycle: DEFAULT_MEAS_CYCLE,
+            sleep_cycle: DEFAULT_SLEEP_CYCLE,
+            charge_high: 0b100,
+            charge_low: 0b000,
+            charge_speed: 0b111,
+        }
+    }
+}
+
+impl TouchConfig {
+    pub fn with_meas_cycle(mut self, cycles: u16) -> Self {
+        self.meas_cycle = cycles;
+        self
+    }
+
+    pub fn with_sleep_cycle(mut self, cycles: u8) -> Self {
+        self.sleep_cycle = cycles;
+        self
+    }
+
+    pub fn with_charge_voltages(mut self, high: u8, low: u8) -> Self {
+        self.charge_high = high;
+        self.charge_low = low;
+        self
+    }
+
+    pub fn with_charge_speed(mut self, speed: u8) -> Self {
+        self.charge_speed = speed;
+        self
+    }
+}
+
+/// Driver for the ESP32's capacitive touch sensor controller.
+///
+/// The controller repeatedly charges and discharges a pad and counts
+/// oscillation cycles during a fixed measurement window; a finger increases
+/// pad capacitance and *lowers* the count. This driver starts the shared
+/// touch FSM, tracks a per-channel baseline set with [`Touch::calibrate`]
+/// (the table starts out zeroed), and reports a press when a channel's count drops
+/// below `baseline - threshold`.
+pub struct Touch {
+    baseline: [u16; TOUCH_CHANNEL_COUNT],
+    armed: u16,
+}
+
+impl Touch {
+    /// Enables the touch FSM with the default measurement/sleep timing and
+    /// returns a controller with an empty baseline table.
+    ///
+    /// Call [`Touch::calibrate`] for each channel you intend to use before
+    /// relying on [`Touch::is_touched`].
+    pub fn new() -> Self {
+        Self::with_config(TouchConfig::default())
+    }
+
+    /// Enables the touch FSM with a custom [`TouchConfig`], trading
+    /// sensitivity against scan rate and noise immunity instead of living
+    /// with the compiled-in defaults.
+    pub fn with_config(config: TouchConfig) -> Self {
+        let sens = SENS::regs();
+        let lpwr = LPWR::regs();
+
+        lpwr.touch_ctrl1().modify(|_, w| unsafe {
+            w.touch_meas_delay().bits(config.meas_cycle);
+            w.touch_drefh().bits(config.charge_high);
+            w.touch_drefl().bits(config.charge_low);
+            w.touch_drange().bits(config.charge_speed)
+        });
+        lpwr.touch_ctrl2().modify(|_, w| unsafe {
+            w.touch_slp_cycle_default().bits(config.sleep_cycle);
+            w.touch_start_fsm_en().set_bit()
+        });
+        sens.sar_touch_ctrl2().modify(|_, w| w.touch_meas_en_clr().set_bit());
+
+        Self {
+            baseline: [0; TOUCH_CHANNEL_COUNT],
+            armed: 0,
+        }
+    }
+
+    fn check_channel(channel: u8) -> Result<(), TouchError> {
+        if TOUCH_RESERVED_CHANNELS & (1 << channel) != 0 {
+            Err(TouchError::ReservedChannel)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Establishes `pin`'s baseline from its current (untouched) count. The
+    /// baseline drifts with temperature and humidity, so re-arm it
+    /// periodically by calling this again.
+    pub fn calibrate<P: TouchPin>(&mut self, pin: &P) -> Result<(), TouchError> {
+        let channel = pin.touch_nr(Internal);
+        Self::check_channel(channel)?;
+        self.baseline[channel as usize] = pin.touch_measurement(Internal);
+        Ok(())
+    }
+
+    /// Reads the raw oscillation count for `pin`.
+    pub fn read<P: TouchPin>(&self, pin: &P) -> u16 {
+        pin.touch_measurement(Internal)
+    }
+
+    /// Returns `true` if `pin`'s most recent count has dropped more than
+    /// `threshold` below its calibrated baseline.
+    pub fn is_touched<P: TouchPin>(&self, pin: &P, threshold: u16) -> bool {
+        let channel = pin.touch_nr(Internal) as usize;
+        let count = pin.touch_measurement(Internal);
+        count < self.baseline[channel].saturating_sub(threshold)
+    }
+
+    /// Arms the touch-done interrupt for `pin`, programming its hardware
+    /// threshold comparator from the calibrated baseline so the interrupt
+    /// fires once the count drops below `baseline - threshold`.
+    pub fn listen<P: TouchPin>(&mut self, pin: &P, threshold: u16) -> Result<(), TouchError> {
+        let channel = pin.touch_nr(Internal);
+        Self::check_channel(channel)?;
+        pin.set_threshold(self.baseline[channel as usize].saturating_sub(threshold), Internal);
+        self.armed |= 1 << channel;
+        Ok(())
+    }
+
+    /// Disarms the touch-done interrupt previously armed with
+    /// [`Touch::listen`].
+    pub fn unlisten<P: TouchPin>(&mut self, pin: &P) {
+        let bit = 1 << pin.touch_nr(Internal);
+        self.armed &= !bit;
+        TOUCH_ARMED.fetch_and(!bit, Ordering::AcqRel);
+    }
+
+    /// Reads which armed channels are currently past their threshold.
+    ///
+    /// This is a live level read of the hardware's pad-active bitset, not a
+    /// latch: it works without [`Touch::enable_interrupt`] and does not
+    /// clear anything, so it won't miss a momentary touch that's still
+    /// pressed, but it also won't catch one that's already released by the
+    /// time you call it. Use [`Touch::wait`] if you need the latched,
+    /// can't-miss-an-edge version.
+    ///
+    /// Returns a bitset with one bit per `touch_nr` (bit N set means channel
+    /// N is currently pressed).
+    pub fn status(&self) -> u16 {
+        let active = SENS::regs().sar_touch_chn_st().read().touch_pad_active().bits() as u16;
+        active & self.armed
+    }
+
+    /// Marks the touch peripheral as a deep-sleep wakeup source, so any
+    /// armed channel can wake the chip. See the `rtc` sleep APIs for
+    /// actually entering deep sleep.
+    pub fn enable_wakeup(&self) {
+        LPWR::regs()
+            .slp_wakeup_cause()
+            .modify(|_, w| w.touch_slp_wakeup_ena().set_bit());
+    }
+}
+
+impl Default for Touch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Deep-sleep wakeup source that wakes when any of the given touch pads
+/// crosses its threshold.
+///
+/// Runs the touch FSM in the sleep power domain and wakes on the same
+/// threshold comparators programmed by [`Touch::listen`]. Pair this with
+/// [`RtcPinHold::hold`] on the same pads so their touch configuration
+/// survives the sleep transition; `RtcPinHold` needs no separate master
+/// enable, so [`hold_all_digital_pads`] is not relevant to touch pins.
+pub struct TouchWakeupSource<'a> {
+    pins: &'a [&'a dyn TouchPin],
+}
+
+impl<'a> TouchWakeupSource<'a> {
+    pub fn new(pins: &'a [&'a dyn TouchPin]) -> Self {
+        Self { pins }
+    }
+
+    /// Enables the touch FSM to keep running during sleep and arms touch as
+    /// a deep-sleep wakeup source.
+    pub fn enable(&self) {
+        LPWR::regs()
+            .touch_ctrl2()
+            .modify(|_, w| w.touch_slp_timer_en().set_bit());
+        LPWR::regs()
+            .slp_wakeup_cause()
+            .modify(|_, w| w.touch_slp_wakeup_ena().set_bit());
+    }
+
+    /// Reads which of this source's pads caused the wake, using the same
+    /// active-status demux as [`handle_touch_interrupt`].
+    pub fn wakeup_status(&self) -> u16 {
+        let active = SENS::regs().sar_touch_chn_st().read().touch_pad_active().bits() as u16;
+        let mask = self
+            .pins
+            .iter()
+            .fold(0u16, |mask, pin| mask | (1 << pin.touch_nr(Internal)));
+
+        active & mask
+    }
+}
+
+/// How [`TouchSensor`] turns its tracked baseline into a press threshold.
+#[derive(Debug, Clone, Copy)]
+pub enum TouchThreshold {
+    /// A fixed count delta below the baseline.
+    Absolute(u16),
+    /// A percentage of the settled baseline, re-evaluated every sample.
+    Percent(u8),
+}
+
+/// Edge reported by [`TouchSensor::sample`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TouchEvent {
+    Pressed,
+    Released,
+}
+
+/// Debounced press/release detection layered on a pad's raw count, tracking
+/// a drifting baseline the way real touch firmwares do.
+///
+/// Each sample `M` updates an exponential moving-average baseline `B` with
+/// `B += (M - B) >> shift` while the pad is not pressed -- the update
+/// freezes while pressed, so a held finger isn't absorbed into the
+/// baseline. Because the ESP32's count *decreases* under a finger, a press
+/// is reported once `M` drops below `B - thresh`, and a release once `M`
+/// rises back above `B - thresh / 2` (the halved margin is hysteresis, to
+/// avoid chatter right at the press threshold).
+pub struct TouchSensor<P: TouchPin> {
+    pin: P,
+    shift: u8,
+    threshold: TouchThreshold,
+    baseline: u16,
+    last_count: u16,
+    pressed: bool,
+}
+
+impl<P: TouchPin> TouchSensor<P> {
+    /// Creates a tracker for `pin`. Call [`TouchSensor::calibrate`] before
+    /// the first [`TouchSensor::sample`] to seed the baseline.
+    pub fn new(pin: P, shift: u8, threshold: TouchThreshold) -> Self {
+        Self {
+            pin,
+            shift,
+            threshold,
+            baseline: 0,
+            last_count: 0,
+            pressed: false,
+        }
+    }
+
+    fn thresh(&self) -> u16 {
+        match self.threshold {
+            TouchThreshold::Absolute(t) => t,
+            TouchThreshold::Percent(pct) => (self.baseline as u32 * pct as u32 / 100) as u16,
+        }
+    }
+
+    /// Seeds the baseline by averaging `samples` idle reads of the pad.
+    pub fn calibrate(&mut self, samples: u16) {
+        let samples = samples.max(1);
+        let sum: u32 = (0..samples)
+            .map(|_| self.pin.touch_measurement(Internal) as u32)
+            .sum();
+
+        self.baseline = (sum / samples as u32) as u16;
+        self.last_count = self.baseline;
+        self.pressed = false;
+    }
+
+    /// Samples the pad once, updates the baseline (unless currently
+    /// pressed), and returns `Some(event)` on a press/release edge.
+    pub fn sample(&mut self) -> Option<TouchEvent> {
+        let count = self.pin.touch_measurement(Internal);
+        self.last_count = count;
+        let thresh = self.thresh();
+
+        if !self.pressed {
+            let delta = (count as i32 - self.baseline as i32) >> self.shift;
+            self.baseline = (self.baseline as i32 + delta) as u16;
+        }
+
+        if !self.pressed && count < self.baseline.saturating_sub(thresh) {
+            self.pressed = true;
+            return Some(TouchEvent::Pressed);
+        }
+
+        if self.pressed && count > self.baseline.saturating_sub(thresh / 2) {
+            self.pressed = false;
+            return Some(TouchEvent::Released);
+        }
+
+        None
+    }
+
+    /// Returns the current deviation of the last sampled count from the
+    /// baseline (~zero while untouched, growing positive as the pad is
+    /// pressed harder, since the count drops under a finger).
+    pub fn deviation(&self) -> i32 {
+        self.baseline as i32 - self.last_count as i32
+    }
+
+    /// Returns whether the pad is currently considered pressed.
+    pub fn is_pressed(&self) -> bool {
+        self.pressed
+    }
+}
+
+/// Result of an [`AcquisitionBank`] read: one freshly-latched count per pad
+/// in the bank, indexed by `touch_nr`.
+pub struct AcquisitionResult {
+    counts: [u16; TOUCH_CHANNEL_COUNT],
+    mask: u16,
+}
+
+impl AcquisitionResult {
+    /// Returns `pin`'s freshly-latched count, or `None` if `pin` wasn't part
+    /// of the bank that produced this result.
+    pub fn get<P: TouchPin>(&self, pin: &P) -> Option<u16> {
+        let channel = pin.touch_nr(Internal);
+        (self.mask & (1 << channel) != 0).then(|| self.counts[channel as usize])
+    }
+}
+
+/// A group of touch pads sampled together in a single FSM sweep.
+///
+/// Reading pads one at a time with [`Touch::read`] can straddle FSM
+/// updates, so two pads in the same "chord" may not reflect the same
+/// instant. An `AcquisitionBank` programs every pad's enable bit together,
+/// kicks off one sweep, and reads every pad's count from that same sweep --
+/// useful for a keypad or slider that needs consistent simultaneous
+/// samples.
+pub struct AcquisitionBank<'a> {
+    pins: &'a [&'a dyn TouchPin],
+}
+
+impl<'a> AcquisitionBank<'a> {
+    pub fn new(pins: &'a [&'a dyn TouchPin]) -> Self {
+        Self { pins }
+    }
+
+    fn enable(&self) -> u16 {
+        let mask = self
+            .pins
+            .iter()
+            .fold(0u16, |mask, pin| mask | (1 << pin.touch_nr(Internal)));
+
+        SENS::regs().sar_touch_enable().modify(|r, w| unsafe {
+            w.touch_pad_worken()
+                .bits(r.touch_pad_worken().bits() | mask)
+        });
+
+        mask
+    }
+
+    fn collect(&self, mask: u16) -> AcquisitionResult {
+        let mut counts = [0u16; TOUCH_CHANNEL_COUNT];
+        for pin in self.pins {
+            counts[pin.touch_nr(Internal) as usize] = pin.touch_measurement(Internal);
+        }
+        AcquisitionResult { counts, mask }
+    }
+
+    /// Kicks off one FSM sweep covering every pad in the bank and blocks
+    /// until the hardware latches its result, then reads every pad's count.
+    ///
+    /// Takes `_touch` only to require that a [`Touch`] controller has
+    /// already been constructed (and so the touch FSM is already running):
+    /// without it, the `touch_meas_done` spin below would never see the
+    /// flag change and would block forever.
+    pub fn read(&self, _touch: &Touch) -> AcquisitionResult {
+        let mask = self.enable();
+
+        let sens = SENS::regs();
+        // The FSM is free-running, so `touch_meas_done` may already be set
+        // from whatever sweep was in progress before this call. Pulse the
+        // clear and then wait to actually observe the flag drop before
+        // waiting for it to be set again -- that way this is correct
+        // regardless of exactly how many cycles the clear pulse takes to
+        // land, rather than assuming the very next read already reflects
+        // it. Only once we've seen a real low-to-high transition do we know
+        // the latched counts came from a single fresh sweep covering every
+        // pad enabled above.
+        sens.sar_touch_ctrl2()
+            .modify(|_, w| w.touch_meas_en_clr().set_bit());
+        while sens.sar_touch_chn_st().read().touch_meas_done().bit_is_set() {}
+        while sens.sar_touch_chn_st().read().touch_meas_done().bit_is_clear() {}
+
+        self.collect(mask)
+    }
+
+    /// Async counterpart of [`AcquisitionBank::read`]. Unlike `read`, it does
+    /// not complete after a single sweep: it completes only once a pad armed
+    /// with [`Touch::listen`] crosses its threshold. The pads in the bank
+    /// must also be armed with [`Touch::listen`] and interrupts enabled with
+    /// [`Touch::enable_interrupt`], since this waits on the same touch-done
+    /// interrupt as [`Touch::wait`].
+    pub async fn read_async(&self, touch: &Touch) -> AcquisitionResult {
+        let mask = self.enable();
+        touch.wait().await;
+        self.collect(mask)
+    }
+}
+
+static TOUCH_WAKER: critical_section::Mutex<RefCell<Option<Waker>>> =
+    critical_section::Mutex::new(RefCell::new(None));
+static TOUCH_ARMED: AtomicU16 = AtomicU16::new(0);
+static TOUCH_STATUS: AtomicU16 = AtomicU16::new(0);
+
+impl Touch {
+    /// Enables the touch-done interrupt for the channels armed with
+    /// [`Touch::listen`], and registers them with the async waker so
+    /// [`Touch::wait`] can complete. You must separately bind
+    /// [`handle_touch_interrupt`] to the `RTC_CORE` vector for the wake to
+    /// ever actually fire.
+    pub fn enable_interrupt(&self) {
+        TOUCH_ARMED.store(self.armed, Ordering::Release);
+        SENS::regs()
+            .sar_touch_ctrl2()
+            .modify(|_, w| w.touch_meas_en().set_bit());
+        LPWR::regs().int_ena().modify(|_, w| w.touch_int_ena().set_bit());
+    }
+
+    /// Disables the touch-done interrupt.
+    pub fn disable_interrupt(&self) {
+        LPWR::regs()
+            .int_ena()
+            .modify(|_, w| w.touch_int_ena().clear_bit());
+    }
+
+    /// Returns a [`Future`] that resolves once any channel armed with
+    /// [`Touch::listen`] crosses its threshold, yielding a bitset (one bit
+    /// per `touch_nr`) of every pad that fired. Requires
+    /// [`Touch::enable_interrupt`] and [`handle_touch_interrupt`] wired to
+    /// the SAR touch interrupt vector.
+    pub fn wait(&self) -> TouchAcquisition<'_> {
+        TouchAcquisition { _touch: self }
+    }
+
+    /// Returns whether `pin`'s bit is set in a status bitset previously
+    /// returned by [`Touch::wait`] or [`Touch::status`].
+    pub fn triggered<P: TouchPin>(&self, status: u16, pin: &P) -> bool {
+        status & (1 << pin.touch_nr(Internal)) != 0
+    }
+}
+
+/// Touch-done interrupt handler.
+///
+/// This crate does not bind it to a vector for you: wire it up yourself to
+/// the `RTC_CORE` interrupt (the vector the SAR touch peripheral raises its
+/// done interrupt on), e.g. with `#[handler]` plus `interrupt::enable`,
+/// before calling [`Touch::enable_interrupt`]. Without it bound,
+/// [`Touch::wait`] and [`AcquisitionBank::read_async`] will never wake.
+///
+/// Demultiplexes which pads triggered by ANDing the latched hardware status
+/// with the set of pads armed via [`Touch::listen`], then clears the
+/// interrupt *before* waking the pending [`TouchAcquisition`] to avoid a
+/// missed-edge race between the clear and the wake.
+pub fn handle_touch_interrupt() {
+    let armed = TOUCH_ARMED.load(Ordering::Acquire);
+    let active = SENS::regs().sar_touch_chn_st().read().touch_pad_active().bits() as u16;
+
+    LPWR::regs().int_clr().write(|w| w.touch_int_clr().set_bit());
+    TOUCH_STATUS.fetch_or(active & armed, Ordering::AcqRel);
+
+    critical_section::with(|cs| {
+        if let Some(waker) = TOUCH_WAKER.borrow_ref_mut(cs).take() {
+            waker.wake();
+        }
+    });
+}
+
+/// Future returned by [`Touch::wait`].
+pub struct TouchAcquisition<'a> {
+    _touch: &'a Touch,
+}
+
+impl Future for TouchAcquisition<'_> {
+    type Output = u16;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let status = TOUCH_STATUS.swap(0, Ordering::AcqRel);
+        if status != 0 {
+            return Poll::Ready(status);
+        }
+
+        critical_section::with(|cs| {
+            *TOUCH_WAKER.borrow_ref_mut(cs) = Some(cx.waker().clone());
+        });
+
+        // Re-check after registering the waker: an interrupt that landed
+        // between the first check and the registration above would
+        // otherwise be missed.
+        let status = TOUCH_STATUS.swap(0, Ordering::AcqRel);
+        if status != 0 {
+            Poll::Ready(status)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
 #[derive(Clone, Copy)]
 pub(crate) enum InterruptStatusRegisterAccess {
     Bank0,
@@ -777,3 +1543,147 @@ impl InterruptStatusRegisterAccess {
         }
     }
 }
+
+/// Pull resistor configuration for a [`PinMuxEntry`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Pull {
+    None,
+    Up,
+    Down,
+}
+
+/// One row of a declarative pin-mux table: the full IO_MUX configuration
+/// (and, optionally, the matrix/native signal routing) for a single GPIO.
+///
+/// A board's pinout can be described as a `const` array of these and applied
+/// in one call with [`apply`], rather than scattered imperative IO_MUX and
+/// matrix calls across bring-up code.
+#[derive(Debug, Clone, Copy)]
+pub struct PinMuxEntry {
+    pub gpio_num: u8,
+    /// IO_MUX alternate function to select. Only honored when both
+    /// `input_signal` and `output_signal` are `None` -- a routed signal
+    /// always determines the pin's `mcu_sel` itself (see [`apply`]).
+    pub function: AlternateFunction,
+    pub pull: Pull,
+    pub drive_strength: u8,
+    pub input_enable: bool,
+    pub input_signal: Option<InputSignal>,
+    pub output_signal: Option<OutputSignal>,
+}
+
+/// Error returned by [`apply`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PinMuxError {
+    /// No such GPIO exists on this chip (e.g. 28..=31).
+    InvalidGpio(u8),
+    /// Two entries in the table target the same GPIO.
+    DuplicateGpio(u8),
+    /// An entry set both `input_signal` and `output_signal`, which would
+    /// leave one of them silently overriding the other's `mcu_sel` choice.
+    /// Split it into two entries (or drop one signal) instead.
+    ConflictingSignals(u8),
+}
+
+fn gpio_exists(gpio_num: u8) -> bool {
+    GPIO_IOMUX_OFFSET
+        .get(gpio_num as usize)
+        .copied()
+        .flatten()
+        .is_some()
+}
+
+/// Programs every IO_MUX register (and matrix/native signal route) described
+/// by `entries`, in order.
+///
+/// The whole table is validated up front -- every `gpio_num` must name a
+/// real pin and no two entries may target the same pin -- before any
+/// register is touched, so a bad table never applies partially.
+pub fn apply(entries: &[PinMuxEntry]) -> Result<(), PinMuxError> {
+    let mut seen: u64 = 0;
+    for entry in entries {
+        if !gpio_exists(entry.gpio_num) {
+            return Err(PinMuxError::InvalidGpio(entry.gpio_num));
+        }
+
+        if entry.input_signal.is_some() && entry.output_signal.is_some() {
+            return Err(PinMuxError::ConflictingSignals(entry.gpio_num));
+        }
+
+        let bit = 1u64 << entry.gpio_num;
+        if seen & bit != 0 {
+            return Err(PinMuxError::DuplicateGpio(entry.gpio_num));
+        }
+        seen |= bit;
+    }
+
+    for entry in entries {
+        apply_entry(entry);
+    }
+
+    Ok(())
+}
+
+fn apply_entry(entry: &PinMuxEntry) {
+    // `apply` already validated every entry's `gpio_num` before calling this.
+    let reg = io_mux_reg(entry.gpio_num).expect("gpio_num was validated by apply()");
+
+    // Wire up the matrix side of any requested signal routing first, so we
+    // know what `mcu_sel` actually needs to end up as: a direct IO_MUX route
+    // takes priority over the matrix (it's strictly faster and frees up the
+    // matrix's input/output selector for other pins), and routing through
+    // the matrix at all requires `mcu_sel` to pick `GPIO_FUNCTION`. When no
+    // signal is routed, `entry.function` is used as-is.
+    //
+    // NOTE: `entry.function` is only honored when `output_signal` and
+    // `input_signal` are both `None` -- a routed signal always determines
+    // `mcu_sel`, overriding `entry.function`.
+    let mut function = entry.function;
+
+    if let Some(signal) = entry.output_signal {
+        function = match direct_io_mux_function_output(signal, entry.gpio_num) {
+            Some(function) => function,
+            None => {
+                // `oen_sel` makes the pad's output-enable follow the
+                // `GPIO_ENABLE` bit we set below instead of the peripheral's
+                // own OE signal; without both of these the pin is routed
+                // but never actually drives.
+                GPIO::regs()
+                    .func_out_sel_cfg(entry.gpio_num as usize)
+                    .modify(|_, w| unsafe {
+                        w.out_sel().bits(signal as OutputSignalType);
+                        w.oen_sel().set_bit()
+                    });
+                GPIO::regs()
+                    .enable_w1ts()
+                    .write(|w| unsafe { w.enable_w1ts().bits(1 << entry.gpio_num) });
+                GPIO_FUNCTION
+            }
+        };
+    }
+
+    if let Some(signal) = entry.input_signal {
+        function = match direct_io_mux_function(signal, entry.gpio_num) {
+            Some(function) => function,
+            None => {
+                GPIO::regs()
+                    .func_in_sel_cfg(signal as usize - FUNC_IN_SEL_OFFSET)
+                    .modify(|_, w| unsafe {
+                        w.sel().set_bit();
+                        w.in_sel().bits(entry.gpio_num)
+                    });
+                GPIO_FUNCTION
+            }
+        };
+    }
+
+    reg.modify(|_, w| unsafe {
+        w.mcu_sel().bits(function as u8);
+        w.fun_ie().bit(entry.input_enable);
+        w.fun_drv().bits(entry.drive_strength);
+        w.fun_wpu().bit(entry.pull == Pull::Up);
+        w.fun_wpd().bit(entry.pull == Pull::Down)
+    });
+}